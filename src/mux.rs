@@ -1,20 +1,56 @@
 use std::marker::PhantomData;
 
 use halo2_proofs::{
-    arithmetic::Field,
+    arithmetic::{Field, PrimeField},
     circuit::{AssignedCell, Chip, Layouter, SimpleFloorPlanner, Value},
     halo2curves::pasta::pallas,
-    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Expression, Fixed, Selector},
+    plonk::{
+        Advice, Circuit, Column, ConstraintSystem, Error, Expression, Fixed, Instance, Selector,
+    },
     poly::Rotation,
 };
 
 type Element<F> = AssignedCell<F, F>;
 
+/// Maximum number of bits `MuxChip::mux_n` can decompose an index into,
+/// i.e. the largest supported `N` is `2^MAX_INDEX_BITS`.
+const MAX_INDEX_BITS: usize = 8;
+
 #[derive(Clone, Debug)]
 pub struct MuxConfig {
-    advice: [Column<Advice>; 3],
-    sel: Column<Fixed>,
+    advice: [Column<Advice>; 4],
+    sel: Column<Advice>,
     s: Selector,
+    s_swap: Selector,
+    idx: Column<Advice>,
+    bits: [Column<Advice>; MAX_INDEX_BITS],
+    s_idx: Selector,
+    constants: Column<Fixed>,
+}
+
+/// Gadget interface for selecting between (or rearranging) elements based on
+/// a boolean selector, mirroring how other halo2 gadgets expose their
+/// instructions behind a trait independent of the concrete chip.
+pub trait MuxInstructions<F: Field> {
+    /// Returns `out = sel ? b : a`.
+    fn mux(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Value<F>,
+        b: Value<F>,
+        sel: Value<F>,
+        row: usize,
+    ) -> Result<Element<F>, Error>;
+
+    /// Returns `(out_a, out_b) = sel ? (b, a) : (a, b)`.
+    fn conditional_swap(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Value<F>,
+        b: Value<F>,
+        sel: Value<F>,
+        row: usize,
+    ) -> Result<(Element<F>, Element<F>), Error>;
 }
 
 #[derive(Clone, Debug)]
@@ -46,9 +82,14 @@ impl MuxChip {
     }
 
     pub fn configure<F: Field>(meta: &mut ConstraintSystem<F>) -> MuxConfig {
-        let advice = (0..3).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        let advice = (0..4).map(|_| meta.advice_column()).collect::<Vec<_>>();
         let s = meta.selector();
-        let sel = meta.fixed_column();
+        let s_swap = meta.selector();
+        let sel = meta.advice_column();
+
+        for column in advice.iter().chain(std::iter::once(&sel)) {
+            meta.enable_equality(*column);
+        }
 
         meta.create_gate("mux", |meta| {
             let s = meta.query_selector(s);
@@ -56,15 +97,86 @@ impl MuxChip {
             let a = meta.query_advice(advice[0], Rotation::cur());
             let b = meta.query_advice(advice[1], Rotation::cur());
             let out = meta.query_advice(advice[2], Rotation::cur());
-            let sel = meta.query_fixed(sel, Rotation::cur());
+            let sel = meta.query_advice(sel, Rotation::cur());
+
+            let bool_check = sel.clone() * (Expression::Constant(F::ONE) - sel.clone());
+
+            vec![
+                s.clone() * (((Expression::Constant(F::ONE) - sel.clone()) * a + sel * b) - out),
+                s * bool_check,
+            ]
+        });
+
+        meta.create_gate("conditional_swap", |meta| {
+            let s_swap = meta.query_selector(s_swap);
+
+            let a = meta.query_advice(advice[0], Rotation::cur());
+            let b = meta.query_advice(advice[1], Rotation::cur());
+            let out_a = meta.query_advice(advice[2], Rotation::cur());
+            let out_b = meta.query_advice(advice[3], Rotation::cur());
+            let sel = meta.query_advice(sel, Rotation::cur());
+
+            let one = Expression::Constant(F::ONE);
+            let bool_check = sel.clone() * (one.clone() - sel.clone());
+            let swapped_a =
+                (one.clone() - sel.clone()) * a.clone() + sel.clone() * b.clone();
+            let swapped_b = (one - sel.clone()) * b + sel * a;
+
+            vec![
+                s_swap.clone() * (swapped_a - out_a),
+                s_swap.clone() * (swapped_b - out_b),
+                s_swap * bool_check,
+            ]
+        });
+
+        let idx = meta.advice_column();
+        let bits = (0..MAX_INDEX_BITS)
+            .map(|_| meta.advice_column())
+            .collect::<Vec<_>>();
+        let s_idx = meta.selector();
+
+        meta.enable_equality(idx);
+        for bit in &bits {
+            meta.enable_equality(*bit);
+        }
+
+        let constants = meta.fixed_column();
+        meta.enable_constant(constants);
+
+        meta.create_gate("index_decompose", |meta| {
+            let s_idx = meta.query_selector(s_idx);
+            let one = Expression::Constant(F::ONE);
+
+            let idx = meta.query_advice(idx, Rotation::cur());
+            let bits = bits
+                .iter()
+                .map(|b| meta.query_advice(*b, Rotation::cur()))
+                .collect::<Vec<_>>();
 
-            vec![s * (((Expression::Constant(F::ONE) - sel.clone()) * a + sel * b) - out)]
+            let mut pow2 = F::ONE;
+            let sum = bits.iter().fold(Expression::Constant(F::ZERO), |acc, bit| {
+                let term = bit.clone() * Expression::Constant(pow2);
+                pow2 = pow2.double();
+                acc + term
+            });
+
+            let mut constraints = vec![s_idx.clone() * (idx - sum)];
+            constraints.extend(
+                bits.into_iter()
+                    .map(|bit| s_idx.clone() * bit.clone() * (one.clone() - bit)),
+            );
+            constraints
         });
 
         MuxConfig {
             advice: advice.try_into().unwrap(),
             sel,
             s,
+            s_swap,
+            idx,
+            bits: bits.try_into().unwrap(),
+            s_idx,
+            constants,
         }
     }
 
@@ -82,7 +194,7 @@ impl MuxChip {
                 self.config.s.enable(&mut region, row)?;
                 let out = a * (Value::known(F::ONE) - sel) + b * sel;
 
-                let _sel = region.assign_fixed(|| "sel", self.config.sel, row, || sel)?;
+                let _sel = region.assign_advice(|| "sel", self.config.sel, row, || sel)?;
                 let _in_a = region.assign_advice(|| "in_a", self.config.advice[0], row, || a)?;
                 let _in_b = region.assign_advice(|| "in_b", self.config.advice[1], row, || b)?;
                 let out = region.assign_advice(|| "out", self.config.advice[2], row, || out)?;
@@ -91,6 +203,257 @@ impl MuxChip {
             },
         )
     }
+
+    /// Like [`MuxChip::mux`], but takes already-assigned cells and
+    /// copy-constrains them into the gate's advice cells, so the mux
+    /// composes soundly with cells produced by other chips instead of
+    /// taking floating `Value`s.
+    pub fn mux_assigned<F: Field>(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &Element<F>,
+        b: &Element<F>,
+        sel: &Element<F>,
+        row: usize,
+    ) -> Result<Element<F>, Error> {
+        layouter.assign_region(
+            || "sel (assigned)",
+            |mut region| {
+                self.config.s.enable(&mut region, row)?;
+
+                let a = a.copy_advice(|| "in_a", &mut region, self.config.advice[0], row)?;
+                let b = b.copy_advice(|| "in_b", &mut region, self.config.advice[1], row)?;
+                let sel = sel.copy_advice(|| "sel", &mut region, self.config.sel, row)?;
+
+                let out = a.value().copied() * (Value::known(F::ONE) - sel.value().copied())
+                    + b.value().copied() * sel.value().copied();
+                let out = region.assign_advice(|| "out", self.config.advice[2], row, || out)?;
+
+                Ok(out)
+            },
+        )
+    }
+
+    /// Muxes each pair `(a[i], b[i])` on the shared selector `sel`, laying
+    /// all `L = a.len()` muxes contiguously in a single region instead of
+    /// one `assign_region` per element. The layouter picks the region's
+    /// placement, so callers no longer need to choose non-overlapping rows.
+    pub fn mux_slice<F: Field>(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &[Element<F>],
+        b: &[Element<F>],
+        sel: &Element<F>,
+    ) -> Result<Vec<Element<F>>, Error> {
+        assert_eq!(a.len(), b.len(), "mux_slice requires a and b of equal length");
+
+        layouter.assign_region(
+            || "mux_slice",
+            |mut region| {
+                a.iter()
+                    .zip(b.iter())
+                    .enumerate()
+                    .map(|(offset, (a, b))| {
+                        self.config.s.enable(&mut region, offset)?;
+
+                        let a = a.copy_advice(|| "in_a", &mut region, self.config.advice[0], offset)?;
+                        let b = b.copy_advice(|| "in_b", &mut region, self.config.advice[1], offset)?;
+                        let sel =
+                            sel.copy_advice(|| "sel", &mut region, self.config.sel, offset)?;
+
+                        let out = a.value().copied()
+                            * (Value::known(F::ONE) - sel.value().copied())
+                            + b.value().copied() * sel.value().copied();
+                        region.assign_advice(|| "out", self.config.advice[2], offset, || out)
+                    })
+                    .collect()
+            },
+        )
+    }
+
+    pub fn conditional_swap<F: Field>(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Value<F>,
+        b: Value<F>,
+        sel: Value<F>,
+        row: usize,
+    ) -> Result<(Element<F>, Element<F>), Error> {
+        layouter.assign_region(
+            || "conditional_swap",
+            |mut region| {
+                self.config.s_swap.enable(&mut region, row)?;
+
+                let out_a = a * (Value::known(F::ONE) - sel) + b * sel;
+                let out_b = b * (Value::known(F::ONE) - sel) + a * sel;
+
+                let _sel = region.assign_advice(|| "sel", self.config.sel, row, || sel)?;
+                let _in_a = region.assign_advice(|| "in_a", self.config.advice[0], row, || a)?;
+                let _in_b = region.assign_advice(|| "in_b", self.config.advice[1], row, || b)?;
+                let out_a = region.assign_advice(|| "out_a", self.config.advice[2], row, || out_a)?;
+                let out_b = region.assign_advice(|| "out_b", self.config.advice[3], row, || out_b)?;
+
+                Ok((out_a, out_b))
+            },
+        )
+    }
+
+    /// Like [`MuxChip::conditional_swap`], but takes already-assigned cells
+    /// and copy-constrains them into the gate's advice cells, so the swap
+    /// composes soundly with cells produced by other chips (e.g. sibling
+    /// ordering in a Merkle path) instead of taking floating `Value`s.
+    pub fn conditional_swap_assigned<F: Field>(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &Element<F>,
+        b: &Element<F>,
+        sel: &Element<F>,
+        row: usize,
+    ) -> Result<(Element<F>, Element<F>), Error> {
+        layouter.assign_region(
+            || "conditional_swap (assigned)",
+            |mut region| {
+                self.config.s_swap.enable(&mut region, row)?;
+
+                let a = a.copy_advice(|| "in_a", &mut region, self.config.advice[0], row)?;
+                let b = b.copy_advice(|| "in_b", &mut region, self.config.advice[1], row)?;
+                let sel = sel.copy_advice(|| "sel", &mut region, self.config.sel, row)?;
+
+                let one_minus_sel = Value::known(F::ONE) - sel.value().copied();
+                let out_a = a.value().copied() * one_minus_sel + b.value().copied() * sel.value().copied();
+                let out_b = b.value().copied() * one_minus_sel + a.value().copied() * sel.value().copied();
+
+                let out_a = region.assign_advice(|| "out_a", self.config.advice[2], row, || out_a)?;
+                let out_b = region.assign_advice(|| "out_b", self.config.advice[3], row, || out_b)?;
+
+                Ok((out_a, out_b))
+            },
+        )
+    }
+
+    /// Decomposes `index` into `MAX_INDEX_BITS` little-endian boolean bit
+    /// cells, binding them to `index` via the `index_decompose` gate, and
+    /// returns the assigned bit cells (least-significant first) so callers
+    /// can copy-constrain them into downstream gates. Bits `k..MAX_INDEX_BITS`
+    /// are constrained to the constant `0`, which range-binds `index` to
+    /// `[0, 2^k)` instead of leaving it a free witness above bit `k`.
+    fn decompose_index<F: PrimeField>(
+        &self,
+        mut layouter: impl Layouter<F>,
+        index: Value<F>,
+        k: usize,
+    ) -> Result<Vec<Element<F>>, Error> {
+        layouter.assign_region(
+            || "decompose index",
+            |mut region| {
+                self.config.s_idx.enable(&mut region, 0)?;
+                region.assign_advice(|| "index", self.config.idx, 0, || index)?;
+
+                (0..MAX_INDEX_BITS)
+                    .map(|i| {
+                        let bit = index.map(|v| bit_at(v, i));
+                        let cell =
+                            region.assign_advice(|| format!("bit {i}"), self.config.bits[i], 0, || bit)?;
+                        if i >= k {
+                            region.constrain_constant(cell.cell(), F::ZERO)?;
+                        }
+                        Ok(cell)
+                    })
+                    .collect::<Result<Vec<_>, Error>>()
+            },
+        )
+    }
+
+    /// Selects the element at position `index` out of `N` `inputs` using a
+    /// binary selection tree: the index is decomposed into
+    /// `k = log2(N)` bit cells, and at level `i` adjacent values are
+    /// pairwise-muxed via `mux_slice` on the decomposed bit cell for that
+    /// level (copy-constrained, so both the selector and the chained
+    /// intermediate values are bound across levels), halving the number of
+    /// candidates until a single output remains. `N` must be a power of two.
+    /// Bits above `k` are constrained to `0`, so `index` is range-bound to
+    /// `[0, N)` rather than merely `index mod N`.
+    pub fn mux_n<F: PrimeField, const N: usize>(
+        &self,
+        mut layouter: impl Layouter<F>,
+        inputs: &[Value<F>],
+        index: Value<F>,
+    ) -> Result<Element<F>, Error> {
+        assert!(N.is_power_of_two() && N > 1, "mux_n requires N to be a power of two > 1");
+        assert!(
+            N <= 1 << MAX_INDEX_BITS,
+            "mux_n supports at most N = 2^MAX_INDEX_BITS = {} inputs",
+            1 << MAX_INDEX_BITS
+        );
+        assert_eq!(inputs.len(), N, "mux_n requires exactly N inputs");
+
+        let k = N.trailing_zeros() as usize;
+        let bits = self.decompose_index(layouter.namespace(|| "decompose index"), index, k)?;
+
+        let mut level = layouter.assign_region(
+            || "mux_n inputs",
+            |mut region| {
+                inputs
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| {
+                        region.assign_advice(|| format!("input {i}"), self.config.advice[0], i, || *v)
+                    })
+                    .collect::<Result<Vec<_>, Error>>()
+            },
+        )?;
+
+        for (i, bit) in bits.iter().take(k).enumerate() {
+            let (a, b): (Vec<_>, Vec<_>) = level
+                .chunks(2)
+                .map(|pair| (pair[0].clone(), pair[1].clone()))
+                .unzip();
+
+            level = self.mux_slice(layouter.namespace(|| format!("mux_n level {i}")), &a, &b, bit)?;
+        }
+
+        Ok(level.into_iter().next().expect("mux_n requires at least one level"))
+    }
+}
+
+fn bit_at<F: PrimeField>(value: F, i: usize) -> F {
+    let repr = value.to_repr();
+    let byte = repr.as_ref()[i / 8];
+    if (byte >> (i % 8)) & 1 == 1 {
+        F::ONE
+    } else {
+        F::ZERO
+    }
+}
+
+impl<F: Field> MuxInstructions<F> for MuxChip {
+    fn mux(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Value<F>,
+        b: Value<F>,
+        sel: Value<F>,
+        row: usize,
+    ) -> Result<Element<F>, Error> {
+        self.mux(layouter, a, b, sel, row)
+    }
+
+    fn conditional_swap(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Value<F>,
+        b: Value<F>,
+        sel: Value<F>,
+        row: usize,
+    ) -> Result<(Element<F>, Element<F>), Error> {
+        self.conditional_swap(layouter, a, b, sel, row)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct MuxCircuitConfig {
+    mux: MuxConfig,
+    instance: Column<Instance>,
 }
 
 #[derive(Clone, Default)]
@@ -101,7 +464,7 @@ struct MuxCircuit<'a, F: Field, const L: usize> {
 }
 
 impl<F: Field, const L: usize> Circuit<F> for MuxCircuit<'_, F, L> {
-    type Config = MuxConfig;
+    type Config = MuxCircuitConfig;
 
     type FloorPlanner = SimpleFloorPlanner;
 
@@ -110,7 +473,10 @@ impl<F: Field, const L: usize> Circuit<F> for MuxCircuit<'_, F, L> {
     }
 
     fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
-        MuxChip::configure(meta)
+        let mux = MuxChip::configure(meta);
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+        MuxCircuitConfig { mux, instance }
     }
 
     fn synthesize(
@@ -118,15 +484,32 @@ impl<F: Field, const L: usize> Circuit<F> for MuxCircuit<'_, F, L> {
         config: Self::Config,
         mut layouter: impl Layouter<F>,
     ) -> Result<(), Error> {
-        let chip = MuxChip::new(config);
-        for i in 0..L {
-            let _ = chip.mux(
-                layouter.namespace(|| format!("mux_{}", i)),
-                self.a[i],
-                self.b[i],
-                self.mux,
-                i,
-            );
+        let chip = MuxChip::new(config.mux.clone());
+
+        let (a_cells, b_cells, sel_cell) = layouter.assign_region(
+            || "inputs",
+            |mut region| {
+                let a_cells = self
+                    .a
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| region.assign_advice(|| format!("a_{i}"), config.mux.advice[0], i, || *v))
+                    .collect::<Result<Vec<_>, Error>>()?;
+                let b_cells = self
+                    .b
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| region.assign_advice(|| format!("b_{i}"), config.mux.advice[1], i, || *v))
+                    .collect::<Result<Vec<_>, Error>>()?;
+                let sel_cell = region.assign_advice(|| "sel", config.mux.sel, 0, || self.mux)?;
+
+                Ok((a_cells, b_cells, sel_cell))
+            },
+        )?;
+
+        let out_cells = chip.mux_slice(layouter.namespace(|| "mux_slice"), &a_cells, &b_cells, &sel_cell)?;
+        for (i, out) in out_cells.iter().enumerate() {
+            layouter.constrain_instance(out.cell(), config.instance, i)?;
         }
         Ok(())
     }
@@ -141,15 +524,315 @@ mod tests {
     #[test]
     fn test() {
         const LEN: usize = 8;
-        let a = [1, 2, 3, 4, 5, 6, 7, 8].map(|x| Value::known(Fp::from(x)));
-        let b = [2, 4, 6, 8, 10, 12, 14, 16].map(|x| Value::known(Fp::from(x)));
+        let a_raw = [1, 2, 3, 4, 5, 6, 7, 8];
+        let b_raw = [2, 4, 6, 8, 10, 12, 14, 16];
+        let a = a_raw.map(|x| Value::known(Fp::from(x)));
+        let b = b_raw.map(|x| Value::known(Fp::from(x)));
 
         let mux = Value::known(Fp::ONE);
 
         let circuit = MuxCircuit::<Fp, LEN> { a: &a, b: &b, mux };
+        // mux = 1 selects b, so the expected output is b itself.
+        let expected = b_raw.map(Fp::from).to_vec();
+        let k = 6;
+
+        MockProver::run(k, &circuit, vec![expected])
+            .unwrap()
+            .assert_satisfied()
+    }
+
+    #[test]
+    fn test_mux_instructions() {
+        #[derive(Clone, Default)]
+        struct DirectMuxCircuit<F: Field> {
+            a: Value<F>,
+            b: Value<F>,
+            sel: Value<F>,
+        }
+
+        impl<F: Field> Circuit<F> for DirectMuxCircuit<F> {
+            type Config = MuxCircuitConfig;
+
+            type FloorPlanner = SimpleFloorPlanner;
+
+            fn without_witnesses(&self) -> Self {
+                Self::default()
+            }
+
+            fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+                let mux = MuxChip::configure(meta);
+                let instance = meta.instance_column();
+                meta.enable_equality(instance);
+                MuxCircuitConfig { mux, instance }
+            }
+
+            fn synthesize(
+                &self,
+                config: Self::Config,
+                mut layouter: impl Layouter<F>,
+            ) -> Result<(), Error> {
+                let chip = MuxChip::new(config.mux);
+                let out = MuxInstructions::mux(
+                    &chip,
+                    layouter.namespace(|| "mux"),
+                    self.a,
+                    self.b,
+                    self.sel,
+                    0,
+                )?;
+                layouter.constrain_instance(out.cell(), config.instance, 0)?;
+                Ok(())
+            }
+        }
+
+        let circuit = DirectMuxCircuit::<Fp> {
+            a: Value::known(Fp::from(3)),
+            b: Value::known(Fp::from(7)),
+            sel: Value::known(Fp::ONE),
+        };
+        let k = 6;
+
+        MockProver::run(k, &circuit, vec![vec![Fp::from(7)]])
+            .unwrap()
+            .assert_satisfied()
+    }
+
+    #[test]
+    fn test_conditional_swap() {
+        #[derive(Clone, Default)]
+        struct SwapCircuit<F: Field> {
+            a: Value<F>,
+            b: Value<F>,
+            sel: Value<F>,
+        }
+
+        impl<F: Field> Circuit<F> for SwapCircuit<F> {
+            type Config = MuxCircuitConfig;
+
+            type FloorPlanner = SimpleFloorPlanner;
+
+            fn without_witnesses(&self) -> Self {
+                Self::default()
+            }
+
+            fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+                let mux = MuxChip::configure(meta);
+                let instance = meta.instance_column();
+                meta.enable_equality(instance);
+                MuxCircuitConfig { mux, instance }
+            }
+
+            fn synthesize(
+                &self,
+                config: Self::Config,
+                mut layouter: impl Layouter<F>,
+            ) -> Result<(), Error> {
+                let chip = MuxChip::new(config.mux);
+                let (out_a, out_b) = chip.conditional_swap(
+                    layouter.namespace(|| "swap"),
+                    self.a,
+                    self.b,
+                    self.sel,
+                    0,
+                )?;
+                layouter.constrain_instance(out_a.cell(), config.instance, 0)?;
+                layouter.constrain_instance(out_b.cell(), config.instance, 1)?;
+                Ok(())
+            }
+        }
+
+        let circuit = SwapCircuit::<Fp> {
+            a: Value::known(Fp::from(3)),
+            b: Value::known(Fp::from(7)),
+            sel: Value::known(Fp::ONE),
+        };
+        // sel = 1 swaps, so out_a = b and out_b = a.
+        let k = 6;
+
+        MockProver::run(k, &circuit, vec![vec![Fp::from(7), Fp::from(3)]])
+            .unwrap()
+            .assert_satisfied()
+    }
+
+    #[test]
+    fn test_mux_n() {
+        #[derive(Clone, Default)]
+        struct MuxNCircuit<F: Field, const N: usize> {
+            inputs: [Value<F>; N],
+            index: Value<F>,
+        }
+
+        impl<F: PrimeField, const N: usize> Circuit<F> for MuxNCircuit<F, N> {
+            type Config = MuxCircuitConfig;
+
+            type FloorPlanner = SimpleFloorPlanner;
+
+            fn without_witnesses(&self) -> Self {
+                Self::default()
+            }
+
+            fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+                let mux = MuxChip::configure(meta);
+                let instance = meta.instance_column();
+                meta.enable_equality(instance);
+                MuxCircuitConfig { mux, instance }
+            }
+
+            fn synthesize(
+                &self,
+                config: Self::Config,
+                mut layouter: impl Layouter<F>,
+            ) -> Result<(), Error> {
+                let chip = MuxChip::new(config.mux);
+                let out = chip.mux_n::<F, N>(
+                    layouter.namespace(|| "mux_n"),
+                    &self.inputs,
+                    self.index,
+                )?;
+                layouter.constrain_instance(out.cell(), config.instance, 0)?;
+                Ok(())
+            }
+        }
+
+        const N: usize = 8;
+        let inputs_raw = [10, 20, 30, 40, 50, 60, 70, 80];
+        let inputs = inputs_raw.map(|x| Value::known(Fp::from(x)));
+
+        let circuit = MuxNCircuit::<Fp, N> {
+            inputs,
+            index: Value::known(Fp::from(5)),
+        };
+        // index = 5 selects inputs[5].
+        let k = 6;
+
+        MockProver::run(k, &circuit, vec![vec![Fp::from(inputs_raw[5])]])
+            .unwrap()
+            .assert_satisfied()
+    }
+
+    #[test]
+    fn test_mux_assigned() {
+        #[derive(Clone, Default)]
+        struct AssignedMuxCircuit<F: Field> {
+            a: Value<F>,
+            b: Value<F>,
+            sel: Value<F>,
+        }
+
+        impl<F: Field> Circuit<F> for AssignedMuxCircuit<F> {
+            type Config = MuxCircuitConfig;
+
+            type FloorPlanner = SimpleFloorPlanner;
+
+            fn without_witnesses(&self) -> Self {
+                Self::default()
+            }
+
+            fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+                let mux = MuxChip::configure(meta);
+                let instance = meta.instance_column();
+                meta.enable_equality(instance);
+                MuxCircuitConfig { mux, instance }
+            }
+
+            fn synthesize(
+                &self,
+                config: Self::Config,
+                mut layouter: impl Layouter<F>,
+            ) -> Result<(), Error> {
+                let chip = MuxChip::new(config.mux.clone());
+
+                let (a, b, sel) = layouter.assign_region(
+                    || "inputs",
+                    |mut region| {
+                        let a = region.assign_advice(|| "a", config.mux.advice[0], 0, || self.a)?;
+                        let b = region.assign_advice(|| "b", config.mux.advice[1], 0, || self.b)?;
+                        let sel =
+                            region.assign_advice(|| "sel", config.mux.advice[2], 0, || self.sel)?;
+                        Ok((a, b, sel))
+                    },
+                )?;
+
+                let out = chip.mux_assigned(layouter.namespace(|| "mux"), &a, &b, &sel, 0)?;
+                layouter.constrain_instance(out.cell(), config.instance, 0)?;
+                Ok(())
+            }
+        }
+
+        let circuit = AssignedMuxCircuit::<Fp> {
+            a: Value::known(Fp::from(3)),
+            b: Value::known(Fp::from(7)),
+            sel: Value::known(Fp::ONE),
+        };
+        // sel = 1 selects b.
+        let k = 6;
+
+        MockProver::run(k, &circuit, vec![vec![Fp::from(7)]])
+            .unwrap()
+            .assert_satisfied()
+    }
+
+
+    #[test]
+    fn test_conditional_swap_assigned() {
+        #[derive(Clone, Default)]
+        struct AssignedSwapCircuit<F: Field> {
+            a: Value<F>,
+            b: Value<F>,
+            sel: Value<F>,
+        }
+
+        impl<F: Field> Circuit<F> for AssignedSwapCircuit<F> {
+            type Config = MuxCircuitConfig;
+
+            type FloorPlanner = SimpleFloorPlanner;
+
+            fn without_witnesses(&self) -> Self {
+                Self::default()
+            }
+
+            fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+                let mux = MuxChip::configure(meta);
+                let instance = meta.instance_column();
+                meta.enable_equality(instance);
+                MuxCircuitConfig { mux, instance }
+            }
+
+            fn synthesize(
+                &self,
+                config: Self::Config,
+                mut layouter: impl Layouter<F>,
+            ) -> Result<(), Error> {
+                let chip = MuxChip::new(config.mux.clone());
+
+                let (a, b, sel) = layouter.assign_region(
+                    || "inputs",
+                    |mut region| {
+                        let a = region.assign_advice(|| "a", config.mux.advice[0], 0, || self.a)?;
+                        let b = region.assign_advice(|| "b", config.mux.advice[1], 0, || self.b)?;
+                        let sel =
+                            region.assign_advice(|| "sel", config.mux.advice[2], 0, || self.sel)?;
+                        Ok((a, b, sel))
+                    },
+                )?;
+
+                let (out_a, out_b) =
+                    chip.conditional_swap_assigned(layouter.namespace(|| "swap"), &a, &b, &sel, 0)?;
+                layouter.constrain_instance(out_a.cell(), config.instance, 0)?;
+                layouter.constrain_instance(out_b.cell(), config.instance, 1)?;
+                Ok(())
+            }
+        }
+
+        let circuit = AssignedSwapCircuit::<Fp> {
+            a: Value::known(Fp::from(3)),
+            b: Value::known(Fp::from(7)),
+            sel: Value::known(Fp::ONE),
+        };
+        // sel = 1 swaps, so out_a = b and out_b = a.
         let k = 6;
 
-        MockProver::run(k, &circuit, vec![])
+        MockProver::run(k, &circuit, vec![vec![Fp::from(7), Fp::from(3)]])
             .unwrap()
             .assert_satisfied()
     }